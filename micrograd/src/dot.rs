@@ -0,0 +1,98 @@
+use std::collections::HashSet;
+
+use crate::tape::{Op, UnaryFn};
+use crate::var::Var;
+
+/// Renders the subgraph reachable from `output` as Graphviz DOT: one record
+/// node per tape entry (showing its index, data, and gradient) and one oval
+/// node per operation, with edges operand -> op -> result. Render forward or
+/// backward state with e.g. `dot -Tpng` after calling `backward()`.
+pub fn to_dot(output: Var) -> String {
+    let tape = output.tape();
+    let mut visited = HashSet::new();
+    let mut stack = vec![output.idx()];
+    let mut body = String::new();
+
+    while let Some(idx) = stack.pop() {
+        if !visited.insert(idx) {
+            continue;
+        }
+
+        body.push_str(&format!(
+            "  v{idx} [label=\"{{ #{idx} | data {data:.4} | grad {grad:.4} }}\"];\n",
+            data = tape.data(idx),
+            grad = tape.grad(idx),
+        ));
+
+        let Some(op) = tape.op(idx) else { continue };
+        let (name, operands) = op_label(op);
+        body.push_str(&format!("  op{idx} [label=\"{name}\", shape=oval];\n"));
+        body.push_str(&format!("  op{idx} -> v{idx};\n"));
+        for operand in operands {
+            body.push_str(&format!("  v{operand} -> op{idx};\n"));
+            stack.push(operand);
+        }
+    }
+
+    format!("digraph G {{\n  rankdir=LR;\n  node [shape=record];\n{body}}}\n")
+}
+
+fn op_label(op: Op) -> (&'static str, Vec<usize>) {
+    match op {
+        Op::Add(a, b) => ("+", vec![a, b]),
+        Op::Sub(a, b) => ("-", vec![a, b]),
+        Op::Mul(a, b) => ("*", vec![a, b]),
+        Op::Div(a, b) => ("/", vec![a, b]),
+        Op::Pow(base, exp) => ("pow", vec![base, exp]),
+        Op::Unary(kind, v) => (unary_name(kind), vec![v]),
+    }
+}
+
+fn unary_name(kind: UnaryFn) -> &'static str {
+    match kind {
+        UnaryFn::Cos => "cos",
+        UnaryFn::Exp => "exp",
+        UnaryFn::Log => "log",
+        UnaryFn::Relu => "relu",
+        UnaryFn::Sigmoid => "sigmoid",
+        UnaryFn::Sin => "sin",
+        UnaryFn::Sqrt => "sqrt",
+        UnaryFn::Tanh => "tanh",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::to_dot;
+    use crate::tape::Tape;
+    use crate::var::Var;
+
+    #[test]
+    fn renders_operands_and_operation_nodes() {
+        let tape = Tape::new();
+        let a = Var::new(&tape, 2.);
+        let b = Var::new(&tape, -3.);
+        let c = a * b;
+
+        let dot = to_dot(c);
+
+        assert!(dot.starts_with("digraph G {"));
+        assert!(dot.contains("data 2.0000"));
+        assert!(dot.contains("data -3.0000"));
+        assert!(dot.contains("data -6.0000"));
+        assert!(dot.contains("label=\"*\""));
+    }
+
+    #[test]
+    fn dedups_a_node_reached_through_two_paths() {
+        let tape = Tape::new();
+        let a = Var::new(&tape, 3.);
+        let b = a + a;
+
+        let dot = to_dot(b);
+
+        // `a` is reached via both of `+`'s operands but must only be
+        // rendered once.
+        assert_eq!(dot.matches("data 3.0000").count(), 1);
+    }
+}
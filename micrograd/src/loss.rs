@@ -0,0 +1,83 @@
+use crate::var::Var;
+
+/// Mean squared error between `predictions` and `targets`.
+pub fn mse<'a>(predictions: &[Var<'a>], targets: &[f64]) -> Var<'a> {
+    assert!(!predictions.is_empty(), "mse: predictions must not be empty");
+    assert_eq!(
+        predictions.len(),
+        targets.len(),
+        "mse: predictions and targets must have the same length"
+    );
+    let tape = predictions[0].tape();
+    let sum = predictions
+        .iter()
+        .zip(targets)
+        .fold(Var::new(tape, 0.), |acc, (pred, target)| {
+            let diff = *pred - *target;
+            acc + diff.powf(2.)
+        });
+    sum / predictions.len() as f64
+}
+
+/// Hinge ("max-margin") loss for binary classification: for each example,
+/// `max(0, 1 - label * score)`, averaged over all examples. `labels` are
+/// expected to be `+1.0` or `-1.0`.
+pub fn max_margin_loss<'a>(scores: &[Var<'a>], labels: &[f64]) -> Var<'a> {
+    assert!(!scores.is_empty(), "max_margin_loss: scores must not be empty");
+    assert_eq!(
+        scores.len(),
+        labels.len(),
+        "max_margin_loss: scores and labels must have the same length"
+    );
+    let tape = scores[0].tape();
+    let sum = scores
+        .iter()
+        .zip(labels)
+        .fold(Var::new(tape, 0.), |acc, (score, label)| {
+            let margin = Var::new(tape, 1.) - *score * *label;
+            acc + margin.relu()
+        });
+    sum / scores.len() as f64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{max_margin_loss, mse};
+    use crate::tape::Tape;
+    use crate::var::Var;
+
+    #[test]
+    fn mse_matches_hand_computed_value() {
+        let tape = Tape::new();
+        let predictions = [Var::new(&tape, 2.), Var::new(&tape, 0.)];
+        let targets = [0., 2.];
+
+        let loss = mse(&predictions, &targets);
+
+        // ((2-0)^2 + (0-2)^2) / 2 = 4
+        assert_eq!(loss.data(), 4.);
+    }
+
+    #[test]
+    fn max_margin_loss_is_zero_when_every_example_clears_the_margin() {
+        let tape = Tape::new();
+        let scores = [Var::new(&tape, 2.), Var::new(&tape, -2.)];
+        let labels = [1., -1.];
+
+        let loss = max_margin_loss(&scores, &labels);
+
+        assert_eq!(loss.data(), 0.);
+    }
+
+    #[test]
+    fn max_margin_loss_penalizes_margin_violations() {
+        let tape = Tape::new();
+        let scores = [Var::new(&tape, 0.5)];
+        let labels = [1.];
+
+        let loss = max_margin_loss(&scores, &labels);
+
+        // max(0, 1 - 1*0.5) = 0.5
+        assert_eq!(loss.data(), 0.5);
+    }
+}
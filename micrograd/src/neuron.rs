@@ -1,17 +1,154 @@
 use rand::Rng;
-use crate::value::{Value, ValueRef};
 
-struct Neuron {
-    weights: Vec<ValueRef>,
-    bias: ValueRef,
+use crate::tape::Tape;
+use crate::var::Var;
+
+pub struct Neuron<'a> {
+    weights: Vec<Var<'a>>,
+    bias: Var<'a>,
 }
 
-impl Neuron {
-    fn new(n: usize) -> Neuron {
+impl<'a> Neuron<'a> {
+    pub fn new(tape: &'a Tape, n: usize) -> Neuron<'a> {
         let mut rng = rand::thread_rng();
         Neuron {
-            weights: (0..n).map(|_| Value::new(rng.gen_range(-1.0..1.0))).collect(),
-            bias: Value::new(rng.gen_range(-1.0..1.0)),
+            weights: (0..n).map(|_| Var::new(tape, rng.gen_range(-1.0..1.0))).collect(),
+            bias: Var::new(tape, rng.gen_range(-1.0..1.0)),
+        }
+    }
+
+    pub fn forward(&self, xs: &[Var<'a>]) -> Var<'a> {
+        assert_eq!(
+            xs.len(),
+            self.weights.len(),
+            "neuron expected {} inputs, got {}",
+            self.weights.len(),
+            xs.len()
+        );
+        let sum = self
+            .weights
+            .iter()
+            .zip(xs)
+            .fold(self.bias, |acc, (w, x)| acc + *w * *x);
+        sum.tanh()
+    }
+
+    pub fn parameters(&self) -> Vec<Var<'a>> {
+        let mut params = self.weights.clone();
+        params.push(self.bias);
+        params
+    }
+}
+
+pub struct Layer<'a> {
+    neurons: Vec<Neuron<'a>>,
+}
+
+impl<'a> Layer<'a> {
+    pub fn new(tape: &'a Tape, nin: usize, nout: usize) -> Layer<'a> {
+        Layer {
+            neurons: (0..nout).map(|_| Neuron::new(tape, nin)).collect(),
+        }
+    }
+
+    pub fn forward(&self, xs: &[Var<'a>]) -> Vec<Var<'a>> {
+        self.neurons.iter().map(|n| n.forward(xs)).collect()
+    }
+
+    pub fn parameters(&self) -> Vec<Var<'a>> {
+        self.neurons.iter().flat_map(|n| n.parameters()).collect()
+    }
+}
+
+/// A multi-layer perceptron: a chain of `Layer`s whose sizes are given by
+/// `shape`, e.g. `MLP::new(&tape, &[3, 4, 4, 1])` for two 4-wide hidden
+/// layers between a 3-input and a 1-output layer.
+pub struct MLP<'a> {
+    layers: Vec<Layer<'a>>,
+}
+
+impl<'a> MLP<'a> {
+    pub fn new(tape: &'a Tape, shape: &[usize]) -> MLP<'a> {
+        MLP {
+            layers: shape
+                .windows(2)
+                .map(|pair| Layer::new(tape, pair[0], pair[1]))
+                .collect(),
+        }
+    }
+
+    pub fn forward(&self, xs: &[Var<'a>]) -> Vec<Var<'a>> {
+        let mut out = xs.to_vec();
+        for layer in &self.layers {
+            out = layer.forward(&out);
+        }
+        out
+    }
+
+    pub fn parameters(&self) -> Vec<Var<'a>> {
+        self.layers.iter().flat_map(|l| l.parameters()).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Neuron, MLP};
+    use crate::loss::max_margin_loss;
+    use crate::optim::step;
+    use crate::tape::Tape;
+    use crate::var::Var;
+
+    #[test]
+    #[should_panic(expected = "neuron expected 2 inputs, got 1")]
+    fn forward_panics_on_mismatched_input_width() {
+        let tape = Tape::new();
+        let neuron = Neuron::new(&tape, 2);
+        let xs = [Var::new(&tape, 1.)];
+        neuron.forward(&xs);
+    }
+
+    fn loss<'a>(tape: &'a Tape, mlp: &MLP<'a>, inputs: &[[f64; 2]], labels: &[f64]) -> Var<'a> {
+        let scores: Vec<Var<'a>> = inputs
+            .iter()
+            .map(|x| {
+                let xs = [Var::new(tape, x[0]), Var::new(tape, x[1])];
+                mlp.forward(&xs)[0]
+            })
+            .collect();
+        max_margin_loss(&scores, labels)
+    }
+
+    #[test]
+    fn training_loop_decreases_loss_on_a_tiny_classification_dataset() {
+        let tape = Tape::new();
+        let mlp = MLP::new(&tape, &[2, 4, 1]);
+
+        // A tiny, linearly separable binary classification dataset: labels
+        // are +1/-1 depending on which side of the line `x + y = 0` a point
+        // falls on.
+        let inputs: [[f64; 2]; 4] = [[2.0, 1.0], [1.5, 2.0], [-2.0, -1.0], [-1.0, -2.0]];
+        let labels = [1.0, 1.0, -1.0, -1.0];
+
+        // Everything pushed to the tape past this point is a single epoch's
+        // forward graph; truncating back to it after each epoch keeps the
+        // tape's size (and so `backward`'s cost) bounded by one epoch's graph
+        // instead of growing with the number of epochs run so far.
+        let checkpoint = tape.len();
+
+        let first_loss = loss(&tape, &mlp, &inputs, &labels).data();
+        tape.truncate(checkpoint);
+
+        let params = mlp.parameters();
+        for _ in 0..50 {
+            loss(&tape, &mlp, &inputs, &labels).backward();
+            step(&params, 0.05);
+            tape.truncate(checkpoint);
         }
+
+        let last_loss = loss(&tape, &mlp, &inputs, &labels).data();
+        assert!(
+            last_loss < first_loss,
+            "expected loss to decrease: first={first_loss}, last={last_loss}"
+        );
     }
 }
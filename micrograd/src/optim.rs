@@ -0,0 +1,34 @@
+use crate::var::Var;
+
+/// One step of vanilla SGD: for every parameter, `data -= lr * grad`, then
+/// zero its gradient so the next `backward()` call starts from a clean
+/// slate.
+pub fn step(params: &[Var], lr: f64) {
+    for p in params {
+        p.set_data(p.data() - lr * p.grad());
+        p.zero_grad();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::step;
+    use crate::tape::Tape;
+    use crate::var::Var;
+
+    #[test]
+    fn step_descends_and_zeroes_gradients() {
+        let tape = Tape::new();
+        let x = Var::new(&tape, 3.);
+        let y = x.powf(2.);
+        y.backward();
+
+        assert_eq!(x.grad(), 6.);
+
+        step(&[x], 0.1);
+
+        // x -= 0.1 * 6 = 0.6
+        assert_eq!(x.data(), 2.4);
+        assert_eq!(x.grad(), 0.);
+    }
+}
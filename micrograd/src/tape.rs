@@ -0,0 +1,256 @@
+use std::cell::RefCell;
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// An operation recorded on a [`Tape`]. Operand fields are indices into the
+/// same tape's node `Vec`, always referring to nodes pushed earlier, so the
+/// tape itself is already in topological order.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub(crate) enum Op {
+    Add(usize, usize),
+    Div(usize, usize),
+    Mul(usize, usize),
+    Pow(usize, usize),
+    Sub(usize, usize),
+    /// Any elementwise function of a single operand. Adding a new one (see
+    /// `UnaryFn`) never requires another arm in `Tape::backward` — only a
+    /// `UnaryFn::forward`/`UnaryFn::backward` case.
+    Unary(UnaryFn, usize),
+}
+
+/// The elementwise functions built on top of `Op::Unary`. A plain tag rather
+/// than a stored `fn` pointer so `Op` keeps round-tripping through serde
+/// (see `Tape`'s `Serialize`/`Deserialize` impls below) — function pointers
+/// aren't serializable.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub(crate) enum UnaryFn {
+    Cos,
+    Exp,
+    Log,
+    Relu,
+    Sigmoid,
+    Sin,
+    Sqrt,
+    Tanh,
+}
+
+impl UnaryFn {
+    fn forward(self, x: f64) -> f64 {
+        match self {
+            UnaryFn::Cos => x.cos(),
+            UnaryFn::Exp => x.exp(),
+            UnaryFn::Log => x.ln(),
+            UnaryFn::Relu => x.max(0.),
+            UnaryFn::Sigmoid => 1. / (1. + (-x).exp()),
+            UnaryFn::Sin => x.sin(),
+            UnaryFn::Sqrt => x.sqrt(),
+            UnaryFn::Tanh => x.tanh(),
+        }
+    }
+
+    /// The local derivative, given both the input `x` and the already
+    /// computed output `out = self.forward(x)` (reused where that's cheaper
+    /// than recomputing it, same as the original hand-written `Tanh`/`Exp`
+    /// arms did).
+    fn backward(self, x: f64, out: f64) -> f64 {
+        match self {
+            UnaryFn::Cos => -x.sin(),
+            UnaryFn::Exp => out,
+            UnaryFn::Log => 1. / x,
+            UnaryFn::Relu => {
+                if x > 0. {
+                    1.
+                } else {
+                    0.
+                }
+            }
+            UnaryFn::Sigmoid => out * (1. - out),
+            UnaryFn::Sin => x.cos(),
+            UnaryFn::Sqrt => 1. / (2. * out),
+            UnaryFn::Tanh => 1. - out * out,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub(crate) struct Node {
+    pub data: f64,
+    pub grad: f64,
+    pub op: Option<Op>,
+}
+
+/// A Wengert list: a single contiguous arena of [`Node`]s that backs every
+/// `Value` handle created from it. Appending a node is O(1) and never
+/// allocates more than a `Vec` push does, and because operands can only
+/// reference already-pushed nodes, the arena is a valid topological order for
+/// free — `backward` sweeps it once in reverse with no pointer dedup or
+/// per-node heap allocation.
+#[derive(Debug, Default)]
+pub struct Tape {
+    nodes: RefCell<Vec<Node>>,
+}
+
+impl Tape {
+    pub fn new() -> Tape {
+        Tape {
+            nodes: RefCell::new(Vec::new()),
+        }
+    }
+
+    pub(crate) fn push_leaf(&self, data: f64) -> usize {
+        self.push(Node {
+            data,
+            grad: 0.,
+            op: None,
+        })
+    }
+
+    pub(crate) fn push_op(&self, data: f64, op: Op) -> usize {
+        self.push(Node {
+            data,
+            grad: 0.,
+            op: Some(op),
+        })
+    }
+
+    pub(crate) fn push_unary(&self, kind: UnaryFn, input: usize) -> usize {
+        let data = kind.forward(self.data(input));
+        self.push_op(data, Op::Unary(kind, input))
+    }
+
+    fn push(&self, node: Node) -> usize {
+        let mut nodes = self.nodes.borrow_mut();
+        nodes.push(node);
+        nodes.len() - 1
+    }
+
+    pub(crate) fn data(&self, idx: usize) -> f64 {
+        self.nodes.borrow()[idx].data
+    }
+
+    pub(crate) fn grad(&self, idx: usize) -> f64 {
+        self.nodes.borrow()[idx].grad
+    }
+
+    pub(crate) fn op(&self, idx: usize) -> Option<Op> {
+        self.nodes.borrow()[idx].op
+    }
+
+    pub(crate) fn set_data(&self, idx: usize, data: f64) {
+        self.nodes.borrow_mut()[idx].data = data;
+    }
+
+    pub(crate) fn set_grad(&self, idx: usize, grad: f64) {
+        self.nodes.borrow_mut()[idx].grad = grad;
+    }
+
+    /// Number of nodes currently on the tape.
+    pub fn len(&self) -> usize {
+        self.nodes.borrow().len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Drops every node at or after `len`, freeing the arena space used by a
+    /// forward pass once it's no longer needed (e.g. between training steps
+    /// that otherwise build a fresh graph on the same tape every iteration).
+    /// Any `Value`/`Var` handle whose index falls at or past `len` is
+    /// dangling after this call and must not be used again.
+    pub fn truncate(&self, len: usize) {
+        self.nodes.borrow_mut().truncate(len);
+    }
+
+    /// Zeroes every gradient, seeds `root`'s gradient with 1, then sweeps the
+    /// node list once in reverse applying each node's local derivative to its
+    /// operands. Because a node only appears after everything that feeds into
+    /// it, a node's gradient is fully accumulated before it is distributed to
+    /// its operands.
+    pub(crate) fn backward(&self, root: usize) {
+        let mut nodes = self.nodes.borrow_mut();
+        for node in nodes.iter_mut() {
+            node.grad = 0.;
+        }
+        nodes[root].grad = 1.;
+
+        for i in (0..nodes.len()).rev() {
+            let node = nodes[i];
+            let Some(op) = node.op else { continue };
+            match op {
+                Op::Add(a, b) => {
+                    nodes[a].grad += node.grad;
+                    nodes[b].grad += node.grad;
+                }
+                Op::Sub(a, b) => {
+                    nodes[a].grad += node.grad;
+                    nodes[b].grad -= node.grad;
+                }
+                Op::Mul(a, b) => {
+                    let (da, db) = (nodes[a].data, nodes[b].data);
+                    nodes[a].grad += node.grad * db;
+                    nodes[b].grad += node.grad * da;
+                }
+                Op::Div(num, den) => {
+                    let (dn, dd) = (nodes[num].data, nodes[den].data);
+                    nodes[num].grad += node.grad / dd;
+                    nodes[den].grad += node.grad * (-dn / (dd * dd));
+                }
+                Op::Pow(base, exp) => {
+                    let (b, e) = (nodes[base].data, nodes[exp].data);
+                    nodes[base].grad += node.grad * e * b.powf(e - 1.);
+                    nodes[exp].grad += node.grad * node.data * b.ln();
+                }
+                Op::Unary(kind, v) => {
+                    nodes[v].grad += node.grad * kind.backward(nodes[v].data, node.data);
+                }
+            }
+        }
+    }
+}
+
+// `RefCell` doesn't implement `Serialize`/`Deserialize`, so the tape is
+// (de)serialized as the flat `Vec<Node>` it wraps — operand fields are
+// already indices into that same `Vec`, so sharing between nodes survives a
+// JSON/bincode round trip for free.
+impl Serialize for Tape {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.nodes.borrow().serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for Tape {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Ok(Tape {
+            nodes: RefCell::new(Vec::<Node>::deserialize(deserializer)?),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Op, Tape};
+
+    #[test]
+    fn json_roundtrip_preserves_sharing_and_gradients() {
+        let tape = Tape::new();
+        let a = tape.push_leaf(3.);
+        let b = tape.push_op(6., Op::Add(a, a));
+        tape.backward(b);
+        let grad_before = tape.grad(a);
+
+        let json = serde_json::to_string(&tape).unwrap();
+        let restored: Tape = serde_json::from_str(&json).unwrap();
+
+        assert!(matches!(restored.op(b), Some(Op::Add(x, y)) if x == a && y == a));
+
+        restored.backward(b);
+        assert_eq!(restored.grad(a), grad_before);
+    }
+}
@@ -0,0 +1,267 @@
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+use crate::tape::Tape;
+use crate::value::Value;
+
+/// A node in the autodiff graph with ergonomic operator overloading.
+///
+/// `Var` wraps a `Value` handle instead of exposing it directly so that
+/// building expressions reads like ordinary arithmetic (`x1 * w1 + x2 * w2 +
+/// b`) rather than chains of `Value::mul`/`Value::add` calls. It deliberately
+/// does not `Deref` to its inner `Value` — reach for `.data()` / `.grad()` to
+/// read through the wrapper.
+#[derive(Debug, Clone, Copy)]
+pub struct Var<'a>(Value<'a>);
+
+impl<'a> Var<'a> {
+    pub fn new(tape: &'a Tape, data: f64) -> Var<'a> {
+        Var(Value::new(tape, data))
+    }
+
+    pub fn data(&self) -> f64 {
+        self.0.data()
+    }
+
+    pub fn grad(&self) -> f64 {
+        self.0.grad()
+    }
+
+    pub fn backward(&self) {
+        self.0.backward();
+    }
+
+    pub fn set_data(&self, data: f64) {
+        self.0.set_data(data);
+    }
+
+    pub fn zero_grad(&self) {
+        self.0.zero_grad();
+    }
+
+    pub(crate) fn tape(&self) -> &'a Tape {
+        self.0.tape()
+    }
+
+    pub(crate) fn idx(&self) -> usize {
+        self.0.idx()
+    }
+
+    pub fn powf(&self, exp: f64) -> Var<'a> {
+        let tape = self.0.tape();
+        Var(Value::pow(self.0, Value::new(tape, exp)))
+    }
+
+    pub fn exp(&self) -> Var<'a> {
+        Var(Value::exp(self.0))
+    }
+
+    pub fn tanh(&self) -> Var<'a> {
+        Var(Value::tanh(self.0))
+    }
+
+    pub fn relu(&self) -> Var<'a> {
+        Var(Value::relu(self.0))
+    }
+
+    pub fn sigmoid(&self) -> Var<'a> {
+        Var(Value::sigmoid(self.0))
+    }
+
+    pub fn log(&self) -> Var<'a> {
+        Var(Value::log(self.0))
+    }
+
+    pub fn sqrt(&self) -> Var<'a> {
+        Var(Value::sqrt(self.0))
+    }
+
+    pub fn sin(&self) -> Var<'a> {
+        Var(Value::sin(self.0))
+    }
+
+    pub fn cos(&self) -> Var<'a> {
+        Var(Value::cos(self.0))
+    }
+}
+
+macro_rules! impl_binary_op {
+    ($trait:ident, $method:ident, $value_fn:path) => {
+        impl<'a> $trait<Var<'a>> for Var<'a> {
+            type Output = Var<'a>;
+            fn $method(self, rhs: Var<'a>) -> Var<'a> {
+                Var($value_fn(self.0, rhs.0))
+            }
+        }
+
+        impl<'a> $trait<&Var<'a>> for &Var<'a> {
+            type Output = Var<'a>;
+            fn $method(self, rhs: &Var<'a>) -> Var<'a> {
+                Var($value_fn(self.0, rhs.0))
+            }
+        }
+
+        impl<'a> $trait<&Var<'a>> for Var<'a> {
+            type Output = Var<'a>;
+            fn $method(self, rhs: &Var<'a>) -> Var<'a> {
+                Var($value_fn(self.0, rhs.0))
+            }
+        }
+
+        impl<'a> $trait<Var<'a>> for &Var<'a> {
+            type Output = Var<'a>;
+            fn $method(self, rhs: Var<'a>) -> Var<'a> {
+                Var($value_fn(self.0, rhs.0))
+            }
+        }
+
+        impl<'a> $trait<f64> for Var<'a> {
+            type Output = Var<'a>;
+            fn $method(self, rhs: f64) -> Var<'a> {
+                let tape = self.0.tape();
+                Var($value_fn(self.0, Value::new(tape, rhs)))
+            }
+        }
+
+        impl<'a> $trait<Var<'a>> for f64 {
+            type Output = Var<'a>;
+            fn $method(self, rhs: Var<'a>) -> Var<'a> {
+                let tape = rhs.0.tape();
+                Var($value_fn(Value::new(tape, self), rhs.0))
+            }
+        }
+    };
+}
+
+impl_binary_op!(Add, add, Value::add);
+impl_binary_op!(Sub, sub, Value::sub);
+impl_binary_op!(Mul, mul, Value::mul);
+impl_binary_op!(Div, div, Value::div);
+
+impl<'a> Neg for Var<'a> {
+    type Output = Var<'a>;
+    fn neg(self) -> Var<'a> {
+        let tape = self.0.tape();
+        Var(Value::mul(self.0, Value::new(tape, -1.)))
+    }
+}
+
+impl<'a> Neg for &Var<'a> {
+    type Output = Var<'a>;
+    fn neg(self) -> Var<'a> {
+        let tape = self.0.tape();
+        Var(Value::mul(self.0, Value::new(tape, -1.)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Var;
+    use crate::tape::Tape;
+
+    fn float_cmp(left: f64, right: f64, tolerance: f64) -> Result<(), String> {
+        if (left - right).abs() < tolerance {
+            Ok(())
+        } else {
+            Err(format!("float assertion `left == right` failed (tolerance={tolerance}).\n   left: {left}\n  right: {right}\n"))
+        }
+    }
+
+    #[test]
+    fn operator_overloading_builds_the_same_graph_as_associated_functions() {
+        let tape = Tape::new();
+        let x1 = Var::new(&tape, 2.);
+        let x2 = Var::new(&tape, 0.);
+        let w1 = Var::new(&tape, -3.);
+        let w2 = Var::new(&tape, 1.);
+        let b = Var::new(&tape, 6.8813735870195432);
+
+        // `&x1 * &w1` exercises the `&Var op &Var` overload; the rest use
+        // owned operands (fine since `Var` is `Copy`) so the lint doesn't
+        // fire on operands that don't need to be by-reference here.
+        #[allow(clippy::op_ref)]
+        let n = &x1 * &w1 + x2 * w2 + b;
+        let o = n.tanh();
+
+        o.backward();
+
+        let tolerance = 0.00001;
+        float_cmp(n.grad(), 0.5, tolerance).unwrap_or_else(|err| panic!("{err}"));
+        float_cmp(x1.grad(), -1.5, tolerance).unwrap_or_else(|err| panic!("{err}"));
+        float_cmp(x2.grad(), 0.5, tolerance).unwrap_or_else(|err| panic!("{err}"));
+        float_cmp(w1.grad(), 1., tolerance).unwrap_or_else(|err| panic!("{err}"));
+        float_cmp(w2.grad(), 0., tolerance).unwrap_or_else(|err| panic!("{err}"));
+    }
+
+    #[test]
+    fn scalar_lifting_on_either_side() {
+        let tape = Tape::new();
+        let x = Var::new(&tape, 3.);
+
+        let a = 2.0 * x + 1.0;
+        assert_eq!(a.data(), 7.);
+
+        a.backward();
+        float_cmp(x.grad(), 2., 0.00001).unwrap_or_else(|err| panic!("{err}"));
+    }
+
+    fn assert_matches_finite_difference(x0: f64, f: impl for<'a> Fn(&'a Var<'a>) -> Var<'a>) {
+        let tape = Tape::new();
+        let x = Var::new(&tape, x0);
+        let y = f(&x);
+        y.backward();
+
+        let h = 1e-6;
+        let tape_plus = Tape::new();
+        let tape_minus = Tape::new();
+        let x_plus = Var::new(&tape_plus, x0 + h);
+        let x_minus = Var::new(&tape_minus, x0 - h);
+        let y_plus = f(&x_plus);
+        let y_minus = f(&x_minus);
+        let numerical = (y_plus.data() - y_minus.data()) / (2. * h);
+
+        float_cmp(x.grad(), numerical, 1e-4)
+            .unwrap_or_else(|err| panic!("at x={x0}: {err}"));
+    }
+
+    #[test]
+    fn relu_gradient_matches_finite_difference() {
+        assert_matches_finite_difference(2., |x| x.relu());
+        assert_matches_finite_difference(-2., |x| x.relu());
+    }
+
+    #[test]
+    fn sigmoid_gradient_matches_finite_difference() {
+        assert_matches_finite_difference(0.3, |x| x.sigmoid());
+    }
+
+    #[test]
+    fn log_gradient_matches_finite_difference() {
+        assert_matches_finite_difference(2., |x| x.log());
+    }
+
+    #[test]
+    fn sqrt_gradient_matches_finite_difference() {
+        assert_matches_finite_difference(4., |x| x.sqrt());
+    }
+
+    #[test]
+    fn sin_gradient_matches_finite_difference() {
+        assert_matches_finite_difference(0.7, |x| x.sin());
+    }
+
+    #[test]
+    fn cos_gradient_matches_finite_difference() {
+        assert_matches_finite_difference(0.7, |x| x.cos());
+    }
+
+    #[test]
+    fn negation() {
+        let tape = Tape::new();
+        let x = Var::new(&tape, 3.);
+        let y = -x;
+        assert_eq!(y.data(), -3.);
+
+        y.backward();
+        assert_eq!(x.grad(), -1.);
+    }
+}